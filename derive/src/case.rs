@@ -0,0 +1,140 @@
+//! A small, self-contained case table for recasing the identifiers `syn`
+//! hands us.  This intentionally duplicates (rather than depends on) the
+//! `Case` logic in `convert_case` itself — see the module docs in `lib.rs`
+//! for why.
+//!
+//! Since Rust identifiers are always ASCII and already conventionally
+//! cased (`PascalCase` for enum variants, `snake_case` for struct fields),
+//! this only needs to split those two specific shapes, not the general
+//! word-boundary detection `convert_case` does for arbitrary input.
+
+/// The subset of `convert_case::Case` that makes sense as a
+/// `#[convert_case(serialize_all = "...")]` target.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Case {
+    Upper,
+    Lower,
+    Title,
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    Cobol,
+    Train,
+}
+
+/// The conventional spellings [`Case::parse`] accepts, for use in
+/// diagnostics when a `serialize_all` value doesn't match any of them.
+pub(crate) const VALID_SPELLINGS: &str = "snake_case, camelCase, PascalCase, kebab-case, \
+     SCREAMING_SNAKE_CASE, SCREAMING-KEBAB-CASE, Train-Case, Title Case, UPPERCASE, lowercase";
+
+impl Case {
+    /// Parses the conventional style name, case-insensitively, the same
+    /// way `convert_case::Case`'s `FromStr` impl does.
+    pub(crate) fn parse(s: &str) -> Option<Case> {
+        Some(match s.to_lowercase().as_str() {
+            "uppercase" => Case::Upper,
+            "lowercase" => Case::Lower,
+            "title case" => Case::Title,
+            "camelcase" | "mixedcase" => Case::Camel,
+            "pascalcase" => Case::Pascal,
+            "snake_case" => Case::Snake,
+            "screaming_snake_case" => Case::ScreamingSnake,
+            "kebab-case" => Case::Kebab,
+            "cobol" | "screaming-kebab-case" => Case::Cobol,
+            "train-case" => Case::Train,
+            _ => return None,
+        })
+    }
+
+    fn delim(&self) -> &'static str {
+        match self {
+            Case::Upper | Case::Lower | Case::Title => " ",
+            Case::Camel | Case::Pascal => "",
+            Case::Snake | Case::ScreamingSnake => "_",
+            Case::Kebab | Case::Cobol | Case::Train => "-",
+        }
+    }
+
+    fn word(&self, i: usize, word: &str) -> String {
+        match self {
+            Case::Upper | Case::ScreamingSnake | Case::Cobol => word.to_ascii_uppercase(),
+            Case::Lower | Case::Snake | Case::Kebab => word.to_ascii_lowercase(),
+            Case::Title | Case::Pascal | Case::Train => capitalize(word),
+            Case::Camel => {
+                if i == 0 {
+                    word.to_ascii_lowercase()
+                } else {
+                    capitalize(word)
+                }
+            }
+        }
+    }
+
+    /// Joins `words` back together using this case's delimiter and
+    /// per-word capitalization.
+    pub(crate) fn convert(&self, words: &[String]) -> String {
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| self.word(i, w))
+            .collect::<Vec<_>>()
+            .join(self.delim())
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => {
+            first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase()
+        }
+        None => String::new(),
+    }
+}
+
+/// Splits a `PascalCase` identifier, as `syn` gives us for enum variant
+/// names, into its component words.
+///
+/// Mirrors the `LowerUpper`/`Acronym`/`DigitLetter`/`LetterDigit`
+/// boundaries `convert_case::Case::Pascal` uses at runtime (see
+/// `src/words.rs`'s ASCII `split`), so that e.g. `XMLHttpRequest` splits
+/// as `["XML", "Http", "Request"]` instead of treating every capital as
+/// its own word.
+pub(crate) fn split_pascal(ident: &str) -> Vec<String> {
+    let bytes = ident.as_bytes();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in bytes.iter().enumerate() {
+        current.push(c as char);
+
+        if let Some(&next) = bytes.get(i + 1) {
+            let boundary_after = (c.is_ascii_lowercase() && next.is_ascii_uppercase())
+                || (c.is_ascii_uppercase()
+                    && next.is_ascii_uppercase()
+                    && bytes.get(i + 2).is_some_and(u8::is_ascii_lowercase))
+                || (c.is_ascii_digit() && next.is_ascii_alphabetic())
+                || (c.is_ascii_alphabetic() && next.is_ascii_digit());
+
+            if boundary_after {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Splits a `snake_case` identifier, as `syn` gives us for struct field
+/// names, into its component words.
+pub(crate) fn split_snake(ident: &str) -> Vec<String> {
+    ident
+        .split('_')
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
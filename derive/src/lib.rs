@@ -0,0 +1,169 @@
+//! Derive macro for [`convert-case`](https://docs.rs/convert_case), the sibling
+//! crate that re-exports it.  This crate is not meant to be depended on directly;
+//! enable it through `convert_case`'s `derive` feature instead.
+//!
+//! This crate intentionally does not depend on `convert_case` itself (that
+//! would make the two crates a dependency cycle once `convert_case`'s
+//! `derive` feature pulls this one back in).  Instead it carries its own
+//! small, ASCII-only case table, the same way `strum_macros` does for
+//! `strum`'s derives.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, LitStr, Variant};
+
+mod case;
+use case::Case;
+
+/// Derives `Display` and `as_str` for an enum by converting each variant's
+/// identifier through this crate's own ASCII case table, using the style
+/// named by the container's `#[convert_case(serialize_all = "...")]`
+/// attribute.
+///
+/// Variant identifiers are split only at lowercase-to-uppercase, acronym
+/// and letter/digit transitions; underscores inside an identifier are not
+/// word boundaries, so a variant named `Page_Load` is not split at the `_`.
+///
+/// A single variant can opt out of the container style with
+/// `#[convert_case(rename = "...")]`.
+///
+/// ```ignore
+/// #[derive(ConvertCase)]
+/// #[convert_case(serialize_all = "kebab-case")]
+/// enum Event {
+///     PageLoad,
+///     #[convert_case(rename = "click")]
+///     ButtonClicked,
+/// }
+///
+/// assert_eq!("page-load", Event::PageLoad.to_string());
+/// assert_eq!("click", Event::ButtonClicked.to_string());
+/// ```
+#[proc_macro_derive(ConvertCase, attributes(convert_case))]
+pub fn derive_convert_case(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let case = match serialize_all(&input.attrs) {
+        Some(Ok(case)) => case,
+        Some(Err(value)) => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                format!(
+                    "unrecognized case style {value:?} in #[convert_case(serialize_all = \"...\")]; \
+                     expected one of: {}",
+                    case::VALID_SPELLINGS,
+                ),
+            )
+            .to_compile_error()
+            .into()
+        }
+        None => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "ConvertCase requires #[convert_case(serialize_all = \"...\")] on the container",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+
+    match &input.data {
+        Data::Enum(data) => derive_enum(&input.ident, &data.variants, case),
+        Data::Struct(data) => derive_struct(&input.ident, &data.fields, case),
+        Data::Union(_) => {
+            syn::Error::new_spanned(&input.ident, "ConvertCase cannot be derived for unions")
+                .to_compile_error()
+                .into()
+        }
+    }
+}
+
+fn derive_enum(ident: &Ident, variants: &Punctuated<Variant, Comma>, case: Case) -> TokenStream {
+    let arms = variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        let name = rename(&variant.attrs)
+            .unwrap_or_else(|| case.convert(&case::split_pascal(&variant_ident.to_string())));
+
+        match &variant.fields {
+            Fields::Unit => quote! { Self::#variant_ident => #name, },
+            Fields::Unnamed(_) => quote! { Self::#variant_ident(..) => #name, },
+            Fields::Named(_) => quote! { Self::#variant_ident { .. } => #name, },
+        }
+    });
+
+    let expanded = quote! {
+        impl #ident {
+            /// Returns the cased name of the active variant.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+fn derive_struct(ident: &Ident, fields: &Fields, case: Case) -> TokenStream {
+    let names = fields.iter().enumerate().map(|(i, field)| {
+        let name = rename(&field.attrs).unwrap_or_else(|| match &field.ident {
+            Some(field_ident) => case.convert(&case::split_snake(&field_ident.to_string())),
+            None => i.to_string(),
+        });
+        quote! { #name }
+    });
+
+    let expanded = quote! {
+        impl #ident {
+            /// The field names of this struct, converted through the case
+            /// named by `#[convert_case(serialize_all = "...")]`.
+            pub const FIELDS: &'static [&'static str] = &[#(#names),*];
+        }
+    };
+
+    expanded.into()
+}
+
+/// Reads `#[convert_case(name = "...")]` off `attrs`, returning the string
+/// value of the first match.
+fn name_value(attrs: &[Attribute], name: &str) -> Option<String> {
+    let mut found = None;
+    for attr in attrs {
+        if !attr.path().is_ident("convert_case") {
+            continue;
+        }
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(name) {
+                let value: LitStr = meta.value()?.parse()?;
+                if found.is_none() {
+                    found = Some(value.value());
+                }
+            }
+            Ok(())
+        });
+    }
+    found
+}
+
+/// Reads `#[convert_case(serialize_all = "...")]` off `attrs`. `None` if
+/// the attribute isn't present at all; `Some(Err(value))` if it's present
+/// but `value` isn't a recognized case spelling.
+fn serialize_all(attrs: &[Attribute]) -> Option<Result<Case, String>> {
+    name_value(attrs, "serialize_all").map(|s| Case::parse(&s).ok_or(s))
+}
+
+fn rename(attrs: &[Attribute]) -> Option<String> {
+    name_value(attrs, "rename")
+}
@@ -0,0 +1,42 @@
+use convert_case_derive::ConvertCase;
+
+#[derive(ConvertCase)]
+#[convert_case(serialize_all = "kebab-case")]
+enum Event {
+    PageLoad,
+    #[convert_case(rename = "click")]
+    ButtonClicked,
+}
+
+#[test]
+fn enum_variants_are_cased() {
+    assert_eq!("page-load", Event::PageLoad.to_string());
+    assert_eq!("click", Event::ButtonClicked.to_string());
+    assert_eq!("page-load", Event::PageLoad.as_str());
+}
+
+#[derive(ConvertCase)]
+#[convert_case(serialize_all = "camelCase")]
+#[allow(dead_code)]
+struct Config {
+    max_retry_count: u32,
+    base_url: String,
+}
+
+#[test]
+fn struct_fields_are_cased() {
+    assert_eq!(&["maxRetryCount", "baseUrl"], Config::FIELDS);
+}
+
+#[derive(ConvertCase)]
+#[convert_case(serialize_all = "snake_case")]
+enum AcronymLeading {
+    XMLHttpRequest,
+    IOStream,
+}
+
+#[test]
+fn acronyms_stay_together() {
+    assert_eq!("xml_http_request", AcronymLeading::XMLHttpRequest.to_string());
+    assert_eq!("io_stream", AcronymLeading::IOStream.to_string());
+}
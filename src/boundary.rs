@@ -0,0 +1,63 @@
+/// A word boundary is a point in a string where a new word is
+/// considered to begin.  Which boundaries are active determines how a
+/// string is split into words before it is recased.
+///
+/// [`Case`](crate::Case)'s built-in presets each use a fixed set of
+/// these. See [`Converter`](crate::Converter) for building a custom set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    /// A space character `' '`.
+    Space,
+    /// An underscore character `'_'`.
+    Underscore,
+    /// A hyphen character `'-'`.
+    Hyphen,
+    /// A lowercase letter followed by an uppercase letter, as in `aA`.
+    LowerUpper,
+    /// A run of two or more uppercase letters followed by a lowercase
+    /// letter, as in `ABc`.  Used to keep acronyms like `IO` in
+    /// `IOStream` together as a single word.
+    Acronym,
+    /// A digit followed by a letter, as in `1a`.
+    DigitLetter,
+    /// A letter followed by a digit, as in `a1`.
+    LetterDigit,
+    /// A single character delimiter of your choosing, consumed the
+    /// same way as [`Boundary::Space`], [`Boundary::Underscore`], and
+    /// [`Boundary::Hyphen`].  With the `unicode` feature disabled, only
+    /// ASCII characters are recognized.
+    Custom(char),
+}
+
+impl Boundary {
+    /// The set of boundaries used by [`Casing::to_case`](crate::Casing::to_case)
+    /// and the default [`Converter`](crate::Converter).
+    pub fn all() -> Vec<Boundary> {
+        vec![
+            Boundary::Space,
+            Boundary::Underscore,
+            Boundary::Hyphen,
+            Boundary::LowerUpper,
+            Boundary::Acronym,
+            Boundary::DigitLetter,
+            Boundary::LetterDigit,
+        ]
+    }
+
+    /// The literal character this boundary consumes as a delimiter, if
+    /// it is one.  Boundaries detected from a transition between two
+    /// characters (`LowerUpper`, `Acronym`, `DigitLetter`, `LetterDigit`)
+    /// have no single delimiter character and return `None`.
+    pub(crate) fn delimiter(&self) -> Option<char> {
+        match self {
+            Boundary::Space => Some(' '),
+            Boundary::Underscore => Some('_'),
+            Boundary::Hyphen => Some('-'),
+            Boundary::Custom(c) => Some(*c),
+            Boundary::LowerUpper
+            | Boundary::Acronym
+            | Boundary::DigitLetter
+            | Boundary::LetterDigit => None,
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use std::fmt;
+use std::str::FromStr;
+
+use strum_macros::EnumIter;
+
+use crate::boundary::Boundary;
+
+/// Defines the type of casing a string can be converted to with
+/// [`Casing`](crate::Casing).
+///
+/// ```
+/// use convert_case::{Case, Casing};
+///
+/// assert_eq!("tetronimo_piece", "Tetronimo Piece".to_case(Case::Snake));
+/// ```
+#[derive(Eq, PartialEq, Debug, Clone, Copy, EnumIter)]
+pub enum Case {
+    /// Uppercase strings are delimited by spaces and all characters are uppercase.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Uppercase](Pattern::Uppercase)
+    /// * Example: `MY VARIABLE NAME`
+    Upper,
+
+    /// Lowercase strings are delimited by spaces and all characters are lowercase.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Lowercase](Pattern::Lowercase)
+    /// * Example: `my variable name`
+    Lower,
+
+    /// Title case strings are delimited by spaces, where each word is capitalized.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Capital](Pattern::Capital)
+    /// * Example: `My Variable Name`
+    Title,
+
+    /// Toggle case strings are delimited by spaces, where each word has its first
+    /// letter lowercase and remaining letters uppercase.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Toggle](Pattern::Toggle)
+    /// * Example: `mY vARIABLE nAME`
+    Toggle,
+
+    /// Camel case strings are lowercase, with the exception of the first letter of
+    /// each word except the first, which is capitalized, with no new characters
+    /// inserted in between words.
+    /// * Boundaries: [LowerUpper](Boundary::LowerUpper), [Acronym](Boundary::Acronym), [DigitLetter](Boundary::DigitLetter), [LetterDigit](Boundary::LetterDigit)
+    /// * Pattern: [Camel](Pattern::Camel)
+    /// * Example: `myVariableName`
+    Camel,
+
+    /// Pascal case strings are capitalized at the start of every word, with no
+    /// new characters inserted in between words.
+    /// * Boundaries: [LowerUpper](Boundary::LowerUpper), [Acronym](Boundary::Acronym), [DigitLetter](Boundary::DigitLetter), [LetterDigit](Boundary::LetterDigit)
+    /// * Pattern: [Capital](Pattern::Capital)
+    /// * Example: `MyVariableName`
+    Pascal,
+
+    /// Identical to Pascal case, provided for those more familiar with this term.
+    /// * Boundaries: [LowerUpper](Boundary::LowerUpper), [Acronym](Boundary::Acronym), [DigitLetter](Boundary::DigitLetter), [LetterDigit](Boundary::LetterDigit)
+    /// * Pattern: [Capital](Pattern::Capital)
+    /// * Example: `MyVariableName`
+    UpperCamel,
+
+    /// Snake case strings are delimited by underscores `_` and are all lowercase.
+    /// * Boundaries: [Underscore](Boundary::Underscore)
+    /// * Pattern: [Lowercase](Pattern::Lowercase)
+    /// * Example: `my_variable_name`
+    Snake,
+
+    /// Screaming snake case strings are delimited by underscores `_` and are all
+    /// uppercase.
+    /// * Boundaries: [Underscore](Boundary::Underscore)
+    /// * Pattern: [Uppercase](Pattern::Uppercase)
+    /// * Example: `MY_VARIABLE_NAME`
+    ScreamingSnake,
+
+    /// Kebab case strings are delimited by hyphens `-` and are all lowercase.
+    /// * Boundaries: [Hyphen](Boundary::Hyphen)
+    /// * Pattern: [Lowercase](Pattern::Lowercase)
+    /// * Example: `my-variable-name`
+    Kebab,
+
+    /// Cobol case strings are delimited by hyphens `-` and are all uppercase.
+    /// * Boundaries: [Hyphen](Boundary::Hyphen)
+    /// * Pattern: [Uppercase](Pattern::Uppercase)
+    /// * Example: `MY-VARIABLE-NAME`
+    Cobol,
+
+    /// Train case strings are delimited by hyphens `-`, where each word is
+    /// capitalized.
+    /// * Boundaries: [Hyphen](Boundary::Hyphen)
+    /// * Pattern: [Capital](Pattern::Capital)
+    /// * Example: `My-Variable-Name`
+    Train,
+
+    /// Alternating case strings are delimited by spaces, where each character
+    /// alternates between uppercase and lowercase.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Alternating](Pattern::Alternating)
+    /// * Example: `mY vArIaBlE nAmE`
+    Alternating,
+}
+
+/// Describes how each word of a string is capitalized when it is
+/// rejoined into a [`Case`].  Used internally to implement [`Case`]'s
+/// conversion logic.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub(crate) enum Pattern {
+    /// Lowercases every character.
+    Lowercase,
+    /// Uppercases every character.
+    Uppercase,
+    /// Uppercases the first letter of each word, lowercases the rest.
+    Capital,
+    /// Lowercases the first word, capitalizes the remaining words.
+    Camel,
+    /// Lowercases the first letter of each word, uppercases the rest.
+    Toggle,
+    /// Alternates the case of each letter across the entire string.
+    Alternating,
+}
+
+impl Case {
+    /// The pattern used to capitalize each word before joining.
+    pub(crate) fn pattern(&self) -> Pattern {
+        use Case::*;
+        match self {
+            Upper => Pattern::Uppercase,
+            Lower => Pattern::Lowercase,
+            Title => Pattern::Capital,
+            Toggle => Pattern::Toggle,
+            Camel => Pattern::Camel,
+            Pascal | UpperCamel => Pattern::Capital,
+            Snake => Pattern::Lowercase,
+            ScreamingSnake => Pattern::Uppercase,
+            Kebab => Pattern::Lowercase,
+            Cobol => Pattern::Uppercase,
+            Train => Pattern::Capital,
+            Alternating => Pattern::Alternating,
+        }
+    }
+
+    /// The delimiter used to join words together.
+    pub(crate) fn delim(&self) -> &'static str {
+        use Case::*;
+        match self {
+            Upper | Lower | Title | Toggle | Alternating => " ",
+            Camel | Pascal | UpperCamel => "",
+            Snake | ScreamingSnake => "_",
+            Kebab | Cobol | Train => "-",
+        }
+    }
+
+    /// The word boundaries used when splitting a string that is
+    /// already known to be in this case, as with `Casing::from_case`.
+    pub(crate) fn boundaries(&self) -> Vec<Boundary> {
+        use Case::*;
+        match self {
+            Upper | Lower | Title | Toggle | Alternating => vec![Boundary::Space],
+            Camel | Pascal | UpperCamel => vec![
+                Boundary::LowerUpper,
+                Boundary::Acronym,
+                Boundary::DigitLetter,
+                Boundary::LetterDigit,
+            ],
+            Snake | ScreamingSnake => vec![Boundary::Underscore],
+            Kebab | Cobol | Train => vec![Boundary::Hyphen],
+        }
+    }
+}
+
+/// An error returned when a string does not match any of the
+/// conventional spellings recognized by [`Case`]'s [`FromStr`] impl.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaseParseError {
+    input: String,
+}
+
+impl fmt::Display for CaseParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unrecognized case style {:?}; expected one of: snake_case, camelCase, \
+             PascalCase, kebab-case, SCREAMING_SNAKE_CASE, SCREAMING-KEBAB-CASE, \
+             Train-Case, Title Case, UPPERCASE, lowercase",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for CaseParseError {}
+
+impl FromStr for Case {
+    type Err = CaseParseError;
+
+    /// Parses a string into a `Case` by matching it against the
+    /// conventional name for each style, case-insensitively.
+    ///
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert_eq!(Case::Snake, "snake_case".parse().unwrap());
+    /// assert_eq!(Case::Camel, "mixedCase".parse().unwrap());
+    /// assert!("not_a_case_name!".parse::<Case>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let case = match s.to_lowercase().as_str() {
+            "uppercase" => Case::Upper,
+            "lowercase" => Case::Lower,
+            "title case" => Case::Title,
+            "camelcase" | "mixedcase" => Case::Camel,
+            "pascalcase" => Case::Pascal,
+            "snake_case" => Case::Snake,
+            "screaming_snake_case" => Case::ScreamingSnake,
+            "kebab-case" => Case::Kebab,
+            "cobol" | "screaming-kebab-case" => Case::Cobol,
+            "train-case" => Case::Train,
+            _ => return Err(CaseParseError { input: s.to_string() }),
+        };
+        Ok(case)
+    }
+}
+
+impl TryFrom<&str> for Case {
+    type Error = CaseParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
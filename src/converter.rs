@@ -0,0 +1,99 @@
+use crate::boundary::Boundary;
+use crate::case::Case;
+use crate::words::split;
+
+/// A configurable case converter, for when the twelve [`Case`] presets
+/// don't split or join words quite the way you need — keeping digit
+/// groups attached, treating `.` or `/` as delimiters, or disabling the
+/// `IOStream` -> `io_stream` acronym folding.
+///
+/// `Casing::to_case` and `Casing::from_case` are themselves implemented
+/// on top of a `Converter`, using [`Boundary::all()`] and a `Case`'s own
+/// [`Boundary`] set respectively as the default boundary presets.
+///
+/// ```
+/// use convert_case::{Boundary, Case, Converter};
+///
+/// let conv = Converter::new()
+///     .set_boundaries(&[Boundary::DigitLetter, Boundary::LetterDigit])
+///     .to_case(Case::Snake);
+/// assert_eq!("v_2_build", conv.convert("V2Build"));
+///
+/// let conv = Converter::new()
+///     .to_case(Case::Snake)
+///     .remove_boundary(Boundary::Acronym);
+/// assert_eq!("iostream", conv.convert("IOStream"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Converter {
+    boundaries: Vec<Boundary>,
+    case: Case,
+    delim: Option<String>,
+}
+
+impl Converter {
+    /// Creates a converter with every boundary active (the same set
+    /// `to_case` uses) and `Case::Snake` as the output case.
+    pub fn new() -> Self {
+        Self {
+            boundaries: Boundary::all(),
+            case: Case::Snake,
+            delim: None,
+        }
+    }
+
+    /// Sets the case words are rendered into, including its default
+    /// delimiter unless overridden with [`Converter::set_delim`].
+    pub fn to_case(mut self, case: Case) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Replaces the active boundaries with the boundaries used by
+    /// `case`.  Mirrors `Casing::from_case`'s parsing rules.
+    pub fn from_case(mut self, case: Case) -> Self {
+        self.boundaries = case.boundaries();
+        self
+    }
+
+    /// Replaces the active boundary set entirely.
+    pub fn set_boundaries(mut self, boundaries: &[Boundary]) -> Self {
+        self.boundaries = boundaries.to_vec();
+        self
+    }
+
+    /// Adds a boundary to the active set, if not already present.
+    pub fn add_boundary(mut self, boundary: Boundary) -> Self {
+        if !self.boundaries.contains(&boundary) {
+            self.boundaries.push(boundary);
+        }
+        self
+    }
+
+    /// Removes a boundary from the active set, if present.
+    pub fn remove_boundary(mut self, boundary: Boundary) -> Self {
+        self.boundaries.retain(|b| *b != boundary);
+        self
+    }
+
+    /// Overrides the delimiter used to join words, instead of the one
+    /// that comes with the output case.
+    pub fn set_delim(mut self, delim: impl Into<String>) -> Self {
+        self.delim = Some(delim.into());
+        self
+    }
+
+    /// Splits `s` along the active boundaries and rejoins the words
+    /// using the output case's pattern and delimiter.
+    pub fn convert(&self, s: &str) -> String {
+        let words = split(s, &self.boundaries);
+        let delim = self.delim.as_deref().unwrap_or_else(|| self.case.delim());
+        self.case.pattern().mutate(&words).join(delim)
+    }
+}
+
+impl Default for Converter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -49,10 +49,17 @@
 //! assert_eq!("weird_var_name", "__weird--var _name-".to_case(Case::Snake));
 //! ```
 //!
+//! By default, the `unicode` feature is enabled and `convert_case` uses the full
+//! Unicode tables to detect word boundaries and change case.  Disabling this
+//! feature switches to an ASCII-only fast path (`make_ascii_uppercase` and
+//! friends), which is smaller and quicker for the common case of ASCII
+//! identifiers, at the cost of the Unicode-specific behavior described below.
+//!
 //! It also works non-ascii characters.  However, no inferences on the language itself is made.
 //! For instance, the diagraph `ij` in dutch will not be capitalized, because it is represented
 //! as two distinct unicode characters.  However, `æ` would be capitalized.
 //! ```
+//! # #[cfg(feature = "unicode")] {
 //! use convert_case::{Case, Casing};
 //!
 //! assert_eq!("granat-äpfel", "GranatÄpfel".to_case(Case::Kebab));
@@ -60,6 +67,7 @@
 //! // The example from str::to_lowercase documentation
 //! let odysseus = "ὈΔΥΣΣΕΎΣ";
 //! assert_eq!("ὀδυσσεύς", odysseus.to_case(Case::Lower));
+//! # }
 //! ```
 //! 
 //! For the purposes of case conversion, characters followed by numerics and vice-versa are 
@@ -73,6 +81,42 @@
 //! assert_eq!("HELLO, WORLD!", "Hello, world!".to_case(Case::Upper));
 //! ```
 //!
+//! # Derive Macro
+//!
+//! Enabling the `derive` feature adds a `ConvertCase` derive macro, which
+//! generates `Display` and `as_str` for an enum by casing each variant's
+//! name, so you don't have to hand write the match arms yourself.
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use convert_case::ConvertCase;
+//!
+//! #[derive(ConvertCase)]
+//! #[convert_case(serialize_all = "kebab-case")]
+//! enum Event {
+//!     PageLoad,
+//!     #[convert_case(rename = "click")]
+//!     ButtonClicked,
+//! }
+//!
+//! assert_eq!("page-load", Event::PageLoad.to_string());
+//! assert_eq!("click", Event::ButtonClicked.to_string());
+//! # }
+//! ```
+//!
+//! # Advanced Conversion
+//!
+//! `to_case` and `from_case` cover the twelve cases above using a fixed set
+//! of boundaries and delimiters.  For anything else, build a [`Converter`]
+//! with exactly the [`Boundary`]s you want.
+//! ```
+//! use convert_case::{Boundary, Case, Converter};
+//!
+//! let conv = Converter::new()
+//!     .to_case(Case::Snake)
+//!     .remove_boundary(Boundary::Acronym);
+//! assert_eq!("iostream", conv.convert("IOStream"));
+//! ```
+//!
 //! # Note on Accuracy
 //!
 //! The `Casing` methods `from_case` and `to_case` do not fail.  Conversion to a case will always
@@ -88,10 +132,15 @@
 //! assert_eq!("my_kebab_like_variable", "myKebab-like-variable".to_case(Case::Snake));
 //! ```
 
+mod boundary;
 mod case;
+mod converter;
 mod words;
-pub use case::Case;
-use words::Words;
+pub use boundary::Boundary;
+pub use case::{Case, CaseParseError};
+pub use converter::Converter;
+#[cfg(feature = "derive")]
+pub use convert_case_derive::ConvertCase;
 
 /// Describes items that can be converted into a case.
 ///
@@ -102,12 +151,15 @@ pub trait Casing {
 
     /// Creates a `FromCasing` struct, which saves information about
     /// how to parse `self` before converting to a case.
+    // Named to mirror `to_case` and read naturally as `s.from_case(x).to_case(y)`,
+    // not as a `from_*` conversion constructor.
+    #[allow(clippy::wrong_self_convention)]
     fn from_case(&self, case: Case) -> FromCasing;
 }
 
 impl Casing for str {
     fn to_case(&self, case: Case) -> String {
-        Words::new(self).into_case(case)
+        Converter::new().to_case(case).convert(self)
     }
 
     fn from_case(&self, case: Case) -> FromCasing {
@@ -117,7 +169,7 @@ impl Casing for str {
 
 impl Casing for String {
     fn to_case(&self, case: Case) -> String {
-        Words::new(self).into_case(case)
+        Converter::new().to_case(case).convert(self)
     }
 
     fn from_case(&self, case: Case) -> FromCasing {
@@ -148,7 +200,10 @@ impl FromCasing {
 
 impl Casing for FromCasing {
     fn to_case(&self, case: Case) -> String {
-        Words::from_casing(&self.name, self.case).into_case(case)
+        Converter::new()
+            .from_case(self.case)
+            .to_case(case)
+            .convert(&self.name)
     }
 
     fn from_case(&self, case: Case) -> Self {
@@ -303,4 +358,61 @@ mod test {
             "ABC-abc_abcAbc ABCAbc".to_case(Case::Snake)
         );
     }
+
+    #[test]
+    fn case_from_str() {
+        assert_eq!(Case::Snake, "snake_case".parse().unwrap());
+        assert_eq!(Case::Camel, "camelCase".parse().unwrap());
+        assert_eq!(Case::Camel, "mixedCase".parse().unwrap());
+        assert_eq!(Case::Pascal, "PascalCase".parse().unwrap());
+        assert_eq!(Case::Kebab, "kebab-case".parse().unwrap());
+        assert_eq!(Case::ScreamingSnake, "SCREAMING_SNAKE_CASE".parse().unwrap());
+        assert_eq!(Case::Cobol, "SCREAMING-KEBAB-CASE".parse().unwrap());
+        assert_eq!(Case::Cobol, "cobol".parse().unwrap());
+        assert_eq!(Case::Train, "Train-Case".parse().unwrap());
+        assert_eq!(Case::Title, "Title Case".parse().unwrap());
+        assert_eq!(Case::Upper, "UPPERCASE".parse().unwrap());
+        assert_eq!(Case::Lower, "lowercase".parse().unwrap());
+        assert_eq!(Case::Snake, Case::try_from("snake_case").unwrap());
+
+        let err = "not_a_case".parse::<Case>().unwrap_err();
+        assert!(err.to_string().contains("snake_case"));
+    }
+
+    #[test]
+    fn converter_default_matches_to_case() {
+        let conv = Converter::new().to_case(Case::Snake);
+        assert_eq!("io_stream".to_case(Case::Snake), conv.convert("io_stream"));
+        assert_eq!("myJSONParser".to_case(Case::Snake), conv.convert("myJSONParser"));
+    }
+
+    #[test]
+    fn converter_custom_boundaries() {
+        let conv = Converter::new()
+            .to_case(Case::Snake)
+            .set_boundaries(&[Boundary::DigitLetter, Boundary::LetterDigit]);
+        assert_eq!("v_2_build", conv.convert("V2Build"));
+    }
+
+    #[test]
+    fn converter_remove_acronym_boundary() {
+        let conv = Converter::new()
+            .to_case(Case::Snake)
+            .remove_boundary(Boundary::Acronym);
+        assert_eq!("iostream", conv.convert("IOStream"));
+    }
+
+    #[test]
+    fn converter_custom_delimiter() {
+        let conv = Converter::new().to_case(Case::Snake).set_delim(".");
+        assert_eq!("my.variable.name", conv.convert("MyVariableName"));
+    }
+
+    #[test]
+    fn converter_custom_boundary_char() {
+        let conv = Converter::new()
+            .to_case(Case::Snake)
+            .set_boundaries(&[Boundary::Custom('.')]);
+        assert_eq!("my_variable_name", conv.convert("my.variable.name"));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,229 @@
+use crate::boundary::Boundary;
+use crate::case::Pattern;
+
+// With the `unicode` feature on (the default), word splitting and
+// recasing are done character-by-character using the full Unicode
+// casing and classification tables, matching the behavior documented
+// at the top of this crate.  With the feature off, the same logic runs
+// byte-by-byte and only ever recognizes and mutates the ASCII range,
+// which avoids pulling in those tables and is noticeably faster for
+// the common case of ASCII identifiers.
+
+#[cfg(feature = "unicode")]
+pub(crate) fn split(s: &str, boundaries: &[Boundary]) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if boundaries.iter().any(|b| b.delimiter() == Some(c)) {
+            push_word(&mut words, &mut current);
+            i += 1;
+            continue;
+        }
+
+        current.push(c);
+
+        if let Some(&next) = chars.get(i + 1) {
+            let boundary_after = (boundaries.contains(&Boundary::LowerUpper)
+                && c.is_lowercase()
+                && next.is_uppercase())
+                || (boundaries.contains(&Boundary::Acronym)
+                    && c.is_uppercase()
+                    && next.is_uppercase()
+                    && chars.get(i + 2).is_some_and(|c| c.is_lowercase()))
+                || (boundaries.contains(&Boundary::DigitLetter)
+                    && c.is_numeric()
+                    && next.is_alphabetic())
+                || (boundaries.contains(&Boundary::LetterDigit)
+                    && c.is_alphabetic()
+                    && next.is_numeric());
+
+            if boundary_after {
+                push_word(&mut words, &mut current);
+            }
+        }
+
+        i += 1;
+    }
+    push_word(&mut words, &mut current);
+
+    words
+}
+
+#[cfg(feature = "unicode")]
+fn push_word(words: &mut Vec<String>, current: &mut String) {
+    if !current.is_empty() {
+        words.push(std::mem::take(current));
+    }
+}
+
+#[cfg(not(feature = "unicode"))]
+pub(crate) fn split(s: &str, boundaries: &[Boundary]) -> Vec<String> {
+    let bytes = s.as_bytes();
+    let mut words = Vec::new();
+    let mut current = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+
+        let is_delim = boundaries
+            .iter()
+            .any(|b| b.delimiter().is_some_and(|d| d.is_ascii() && d as u8 == c));
+        if is_delim {
+            push_word(&mut words, &mut current);
+            i += 1;
+            continue;
+        }
+
+        current.push(c);
+
+        if let Some(&next) = bytes.get(i + 1) {
+            let boundary_after = (boundaries.contains(&Boundary::LowerUpper)
+                && c.is_ascii_lowercase()
+                && next.is_ascii_uppercase())
+                || (boundaries.contains(&Boundary::Acronym)
+                    && c.is_ascii_uppercase()
+                    && next.is_ascii_uppercase()
+                    && bytes.get(i + 2).is_some_and(|c| c.is_ascii_lowercase()))
+                || (boundaries.contains(&Boundary::DigitLetter)
+                    && c.is_ascii_digit()
+                    && next.is_ascii_alphabetic())
+                || (boundaries.contains(&Boundary::LetterDigit)
+                    && c.is_ascii_alphabetic()
+                    && next.is_ascii_digit());
+
+            if boundary_after {
+                push_word(&mut words, &mut current);
+            }
+        }
+
+        i += 1;
+    }
+    push_word(&mut words, &mut current);
+
+    words
+}
+
+#[cfg(not(feature = "unicode"))]
+fn push_word(words: &mut Vec<String>, current: &mut Vec<u8>) {
+    if !current.is_empty() {
+        let bytes = std::mem::take(current);
+        words.push(String::from_utf8(bytes).expect("ascii fast path only mutates ASCII bytes"));
+    }
+}
+
+impl Pattern {
+    pub(crate) fn mutate(&self, words: &[String]) -> Vec<String> {
+        match self {
+            Pattern::Lowercase => words.iter().map(|w| lower(w)).collect(),
+            Pattern::Uppercase => words.iter().map(|w| upper(w)).collect(),
+            Pattern::Capital => words.iter().map(|w| capitalize(w)).collect(),
+            Pattern::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| if i == 0 { lower(w) } else { capitalize(w) })
+                .collect(),
+            Pattern::Toggle => words.iter().map(|w| toggle(w)).collect(),
+            Pattern::Alternating => {
+                let mut is_upper = false;
+                words
+                    .iter()
+                    .map(|w| {
+                        w.chars()
+                            .map(|c| {
+                                if is_alpha(c) {
+                                    let s = if is_upper {
+                                        upper(&c.to_string())
+                                    } else {
+                                        lower(&c.to_string())
+                                    };
+                                    is_upper = !is_upper;
+                                    s
+                                } else {
+                                    c.to_string()
+                                }
+                            })
+                            .collect()
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "unicode")]
+fn lower(s: &str) -> String {
+    s.to_lowercase()
+}
+
+#[cfg(feature = "unicode")]
+fn upper(s: &str) -> String {
+    s.to_uppercase()
+}
+
+#[cfg(feature = "unicode")]
+fn is_alpha(c: char) -> bool {
+    c.is_alphabetic()
+}
+
+#[cfg(not(feature = "unicode"))]
+fn lower(s: &str) -> String {
+    s.to_ascii_lowercase()
+}
+
+#[cfg(not(feature = "unicode"))]
+fn upper(s: &str) -> String {
+    s.to_ascii_uppercase()
+}
+
+#[cfg(not(feature = "unicode"))]
+fn is_alpha(c: char) -> bool {
+    c.is_ascii_alphabetic()
+}
+
+#[cfg(feature = "unicode")]
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + &lower(chars.as_str()),
+        None => String::new(),
+    }
+}
+
+#[cfg(feature = "unicode")]
+fn toggle(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().collect::<String>() + &upper(chars.as_str()),
+        None => String::new(),
+    }
+}
+
+#[cfg(not(feature = "unicode"))]
+fn capitalize(word: &str) -> String {
+    let mut bytes = word.as_bytes().to_vec();
+    if let Some(first) = bytes.first_mut() {
+        first.make_ascii_uppercase();
+    }
+    for b in bytes.iter_mut().skip(1) {
+        b.make_ascii_lowercase();
+    }
+    String::from_utf8(bytes).expect("ascii fast path only mutates ASCII bytes")
+}
+
+#[cfg(not(feature = "unicode"))]
+fn toggle(word: &str) -> String {
+    let mut bytes = word.as_bytes().to_vec();
+    if let Some(first) = bytes.first_mut() {
+        first.make_ascii_lowercase();
+    }
+    for b in bytes.iter_mut().skip(1) {
+        b.make_ascii_uppercase();
+    }
+    String::from_utf8(bytes).expect("ascii fast path only mutates ASCII bytes")
+}